@@ -0,0 +1,266 @@
+use std::{
+    collections::HashSet,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use tokio::{
+    sync::{mpsc, Mutex},
+    task::JoinHandle,
+};
+
+use crate::builds::Rune;
+use crate::source::SourceItem;
+
+/// Mirrors `ChampR::logs`' entry shape: `(source, message)`.
+pub type LogItem = (String, String);
+
+/// Result of fetching one source's build for the in-flight champion/position.
+struct SourceFetch {
+    source: String,
+    result: Result<Vec<Rune>, String>,
+}
+
+/// Merges runes/builds from every selected source into a single ranked,
+/// deduplicated list, the way build-o-tron fans a task out per source and
+/// collects the results over an `mpsc` channel.
+///
+/// Each call bumps a generation counter and aborts whatever fetch tasks the
+/// previous call spawned, so a champion change mid-load cancels the stale
+/// fetches outright instead of merely discarding their results once they
+/// eventually finish. In-flight handles are tagged with the generation that
+/// spawned them, so a call can never abort or clear a newer call's handles —
+/// only its own.
+#[derive(Clone)]
+pub struct BuildAggregator {
+    generation: Arc<AtomicU64>,
+    logs: Arc<Mutex<Vec<LogItem>>>,
+    in_flight: Arc<Mutex<Vec<(u64, JoinHandle<()>)>>>,
+}
+
+impl BuildAggregator {
+    pub fn new(logs: Arc<Mutex<Vec<LogItem>>>) -> Self {
+        Self {
+            generation: Arc::new(AtomicU64::new(0)),
+            logs,
+            in_flight: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Concurrently fetches `sources` for `champion_id`/`position`, ranks the
+    /// combined results, and tags each entry with its originating source.
+    pub async fn aggregate_for_champion(
+        &self,
+        champion_id: u64,
+        position: &str,
+        sources: &[SourceItem],
+    ) -> Vec<Rune> {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        // A new champion/position supersedes whatever any previous call is
+        // still waiting on; abort those tasks outright instead of letting
+        // them run to completion only to have their results discarded. Only
+        // handles from strictly older generations are touched, so this can
+        // never abort a call that started after this one.
+        self.in_flight.lock().await.retain(|(gen, handle)| {
+            if *gen < generation {
+                handle.abort();
+                false
+            } else {
+                true
+            }
+        });
+
+        let (tx, mut rx) = mpsc::unbounded_channel::<SourceFetch>();
+        let mut handles = Vec::with_capacity(sources.len());
+        for source in sources {
+            let tx = tx.clone();
+            let source_name = source.name.clone();
+            let position = position.to_string();
+            handles.push((
+                generation,
+                tokio::task::spawn(async move {
+                    let result = crate::source::fetch_runes(&source_name, champion_id, &position)
+                        .await
+                        .map_err(|e| e.to_string());
+                    let _ = tx.send(SourceFetch {
+                        source: source_name,
+                        result,
+                    });
+                }),
+            ));
+        }
+        self.in_flight.lock().await.extend(handles);
+        drop(tx);
+
+        let mut fetched = Vec::new();
+        while let Some(fetch) = rx.recv().await {
+            // Each call has its own channel, so only this call's tasks send
+            // here. Once a newer call has bumped the generation this call is
+            // stale; drop its remaining results instead of logging them or
+            // adding them to what it returns.
+            if generation != self.generation.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            match fetch.result {
+                Ok(runes) => {
+                    self.logs
+                        .lock()
+                        .await
+                        .push((fetch.source.clone(), "ok".to_string()));
+                    fetched.extend(runes.into_iter().map(|mut r| {
+                        r.source = fetch.source.clone();
+                        r
+                    }));
+                }
+                Err(e) => {
+                    self.logs.lock().await.push((fetch.source.clone(), e));
+                }
+            }
+        }
+
+        // Only drop this call's own (by now completed) handles — a newer
+        // call may already have handles of its own sitting in `in_flight`.
+        self.in_flight.lock().await.retain(|(gen, _)| *gen != generation);
+        rank_and_dedupe(fetched)
+    }
+}
+
+/// Ranks candidates by win rate (pick rate as a tiebreaker) and collapses
+/// duplicate pages (same perk selection) down to the best-ranked one.
+///
+/// Sources that don't report a win/pick rate leave both at their `Rune`
+/// default of `0.0`; since `sort_by` is stable, ties there fall back to
+/// whichever source's task happened to land first, same as before sources
+/// carried rank data at all.
+fn rank_and_dedupe(mut runes: Vec<Rune>) -> Vec<Rune> {
+    runes.sort_by(|a, b| {
+        b.win_rate
+            .partial_cmp(&a.win_rate)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| {
+                b.pick_rate
+                    .partial_cmp(&a.pick_rate)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    });
+
+    let mut seen = HashSet::new();
+    runes
+        .into_iter()
+        .filter(|rune| seen.insert(rune.selected_perk_ids.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rune(source: &str, perk_ids: &[u64], win_rate: f64, pick_rate: f64) -> Rune {
+        Rune {
+            name: source.to_string(),
+            selected_perk_ids: perk_ids.to_vec(),
+            source: source.to_string(),
+            win_rate,
+            pick_rate,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn prefers_highest_win_rate_on_conflict() {
+        let ranked = rank_and_dedupe(vec![
+            rune("op.gg", &[1, 2, 3], 0.51, 0.20),
+            rune("u.gg", &[1, 2, 3], 0.58, 0.15),
+        ]);
+
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].source, "u.gg");
+    }
+
+    #[test]
+    fn falls_back_to_pick_rate_then_arrival_order_on_tie() {
+        let ranked = rank_and_dedupe(vec![
+            rune("a", &[1, 2, 3], 0.5, 0.10),
+            rune("b", &[1, 2, 3], 0.5, 0.30),
+            rune("c", &[4, 5, 6], 0.5, 0.10),
+            rune("d", &[7, 8, 9], 0.5, 0.10),
+        ]);
+
+        assert_eq!(ranked.len(), 3);
+        assert_eq!(ranked[0].source, "b");
+        assert_eq!(ranked[1].source, "c");
+        assert_eq!(ranked[2].source, "d");
+    }
+
+    #[test]
+    fn keeps_distinct_perk_selections() {
+        let ranked = rank_and_dedupe(vec![
+            rune("a", &[1, 2, 3], 0.5, 0.5),
+            rune("b", &[4, 5, 6], 0.5, 0.5),
+        ]);
+
+        assert_eq!(ranked.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn a_stale_calls_trailing_cleanup_does_not_clear_a_newer_calls_handles() {
+        let aggregator = BuildAggregator::new(Arc::new(Mutex::new(Vec::new())));
+
+        // Call A (generation 1) is mid-flight...
+        let a_handle = tokio::task::spawn(std::future::pending::<()>());
+        aggregator.in_flight.lock().await.push((1, a_handle));
+
+        // ...call B (generation 2) has since started and has its own handle
+        // parked, because A hasn't reached its trailing cleanup yet.
+        let b_handle = tokio::task::spawn(std::future::pending::<()>());
+        aggregator.in_flight.lock().await.push((2, b_handle));
+
+        // A's own trailing cleanup now runs. The old code did a blanket
+        // `.clear()` here, which would have wiped B's handle too.
+        aggregator.in_flight.lock().await.retain(|(gen, _)| *gen != 1);
+
+        let remaining: Vec<u64> = aggregator
+            .in_flight
+            .lock()
+            .await
+            .iter()
+            .map(|(gen, _)| *gen)
+            .collect();
+        assert_eq!(remaining, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn starting_a_new_call_only_aborts_strictly_older_generations() {
+        let aggregator = BuildAggregator::new(Arc::new(Mutex::new(Vec::new())));
+
+        let older = tokio::task::spawn(std::future::pending::<()>());
+        let same_gen = tokio::task::spawn(std::future::pending::<()>());
+        aggregator.in_flight.lock().await.push((1, older));
+        aggregator.in_flight.lock().await.push((2, same_gen));
+
+        // A call starting as generation 2 must abort generation 1's handle
+        // but leave any (impossible in practice, but checked for safety)
+        // same-or-newer-generation handle alone.
+        aggregator.in_flight.lock().await.retain(|(gen, handle)| {
+            if *gen < 2 {
+                handle.abort();
+                false
+            } else {
+                true
+            }
+        });
+
+        let remaining: Vec<u64> = aggregator
+            .in_flight
+            .lock()
+            .await
+            .iter()
+            .map(|(gen, _)| *gen)
+            .collect();
+        assert_eq!(remaining, vec![2]);
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// A single rune page as surfaced by the aggregated data sources. `name` and
+/// the style/perk fields map 1:1 onto the LCU's `POST /lol-perks/v1/pages`
+/// payload; `source`, `win_rate` and `pick_rate` are not part of it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Rune {
+    pub name: String,
+    #[serde(rename = "primaryStyleId")]
+    pub primary_style_id: u64,
+    #[serde(rename = "subStyleId")]
+    pub sub_style_id: u64,
+    #[serde(rename = "selectedPerkIds")]
+    pub selected_perk_ids: Vec<u64>,
+    /// Which data source this page came from, e.g. `"op.gg"` or `"u.gg"`.
+    /// Only meaningful for aggregated results; `LcuRestClient` leaves it out
+    /// of the page it posts to the LCU.
+    #[serde(default)]
+    pub source: String,
+    /// Win rate reported by `source`, as a fraction in `[0, 1]`. `0.0` means
+    /// the source didn't report one, not that the build loses every game.
+    #[serde(default)]
+    pub win_rate: f64,
+    /// Pick rate reported by `source`, as a fraction in `[0, 1]`. Used as the
+    /// `BuildAggregator` tiebreaker when two sources report the same win rate.
+    #[serde(default)]
+    pub pick_rate: f64,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Persisted user preferences for `ChampR`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Config {
+    /// Skips LCU certificate verification instead of pinning Riot's root cert.
+    ///
+    /// Defaults to `true` for now: `certs/riotgames.pem` is a placeholder, not
+    /// the genuine Riot-published root, so pinning against it by default would
+    /// reject every real LCU connection instead of just blocking MITM. Flip
+    /// this default back to `false` once the real cert is vendored.
+    #[serde(default = "default_insecure_lcu_tls")]
+    pub insecure_lcu_tls: bool,
+}
+
+fn default_insecure_lcu_tls() -> bool {
+    true
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            insecure_lcu_tls: default_insecure_lcu_tls(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+/// A decoded `OnJsonApiEvent` push frame, routed by its `uri`.
+///
+/// The LCU sends frames shaped like `[8, "OnJsonApiEvent", { uri, eventType, data }]`;
+/// `RawEventPayload` mirrors the inner object and `route_event` turns it into
+/// one of the variants below.
+#[derive(Clone, Debug)]
+pub enum LcuEvent {
+    ChampSelectSession(ChampSelectSession),
+    GameflowPhase(String),
+    CurrentSummoner(CurrentSummoner),
+    Unknown { uri: String, data: Value },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct RawEventPayload {
+    pub uri: String,
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+    pub data: Value,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ChampSelectSession {
+    #[serde(rename = "localPlayerCellId")]
+    pub local_player_cell_id: u64,
+    #[serde(rename = "myTeam", default)]
+    pub my_team: Vec<ChampSelectTeamMember>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ChampSelectTeamMember {
+    #[serde(rename = "cellId")]
+    pub cell_id: u64,
+    #[serde(rename = "championId")]
+    pub champion_id: u64,
+}
+
+impl ChampSelectSession {
+    /// The champion id the local summoner currently has locked/hovered, if any.
+    pub fn current_champion_id(&self) -> Option<u64> {
+        self.my_team
+            .iter()
+            .find(|m| m.cell_id == self.local_player_cell_id && m.champion_id != 0)
+            .map(|m| m.champion_id)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct CurrentSummoner {
+    #[serde(rename = "summonerId")]
+    pub summoner_id: u64,
+    #[serde(rename = "displayName", default)]
+    pub display_name: String,
+}
+
+/// Parses one `[8, "OnJsonApiEvent", {...}]` text frame into a typed `LcuEvent`.
+///
+/// Returns `None` for frames that aren't `OnJsonApiEvent` pushes (e.g. the `[5, ...]`
+/// subscribe ack), since those carry nothing the rest of `ChampR` needs to react to.
+pub fn parse_frame(text: &str) -> Option<LcuEvent> {
+    let frame: Value = serde_json::from_str(text).ok()?;
+    let arr = frame.as_array()?;
+    if arr.len() != 3 || arr[1].as_str() != Some("OnJsonApiEvent") {
+        return None;
+    }
+
+    let payload: RawEventPayload = serde_json::from_value(arr[2].clone()).ok()?;
+    Some(route_event(payload))
+}
+
+/// Matches a raw payload's `uri` against the LCU endpoints `ChampR` cares about.
+pub fn route_event(payload: RawEventPayload) -> LcuEvent {
+    match payload.uri.as_str() {
+        "/lol-champ-select/v1/session" => {
+            match serde_json::from_value::<ChampSelectSession>(payload.data) {
+                Ok(session) => LcuEvent::ChampSelectSession(session),
+                Err(_) => LcuEvent::Unknown {
+                    uri: payload.uri,
+                    data: Value::Null,
+                },
+            }
+        }
+        "/lol-gameflow/v1/gameflow-phase" => {
+            let phase = payload.data.as_str().unwrap_or_default().to_string();
+            LcuEvent::GameflowPhase(phase)
+        }
+        "/lol-summoner/v1/current-summoner" => {
+            match serde_json::from_value::<CurrentSummoner>(payload.data) {
+                Ok(summoner) => LcuEvent::CurrentSummoner(summoner),
+                Err(_) => LcuEvent::Unknown {
+                    uri: payload.uri,
+                    data: Value::Null,
+                },
+            }
+        }
+        _ => LcuEvent::Unknown {
+            uri: payload.uri,
+            data: payload.data,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_champ_select_session() {
+        let frame = r#"[8, "OnJsonApiEvent", {
+            "uri": "/lol-champ-select/v1/session",
+            "eventType": "Update",
+            "data": { "localPlayerCellId": 1, "myTeam": [{ "cellId": 1, "championId": 99 }] }
+        }]"#;
+
+        match parse_frame(frame) {
+            Some(LcuEvent::ChampSelectSession(session)) => {
+                assert_eq!(session.current_champion_id(), Some(99));
+            }
+            other => panic!("expected ChampSelectSession, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn ignores_non_api_event_frames() {
+        assert!(parse_frame(r#"[5, "OnJsonApiEvent"]"#).is_none());
+    }
+}
@@ -0,0 +1,189 @@
+use std::sync::{Arc, Mutex};
+
+use http::HeaderValue;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::builds::Rune;
+
+/// Authenticated REST client for pushing a selected build into the live LCU.
+///
+/// Reuses the same `auth_url` (`riot:<password>@127.0.0.1:<port>`) basic-auth
+/// credentials `LcuClient::conn_ws` already parses for the websocket, and keeps a
+/// single pooled `reqwest::Client` so repeated applies reuse the TLS connection
+/// instead of redoing the handshake every time.
+///
+/// Pins Riot's LCU root certificate the same way `LcuClient::conn_ws` does
+/// when `insecure_tls` is `false`; when it is `true` any certificate is
+/// accepted. `config::Config::insecure_lcu_tls` defaults to `true`, so the
+/// permissive behavior is the default.
+#[derive(Clone, Debug)]
+pub struct LcuRestClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CurrentRunePage {
+    id: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct NewRunePage<'a> {
+    name: &'a str,
+    #[serde(rename = "primaryStyleId")]
+    primary_style_id: u64,
+    #[serde(rename = "subStyleId")]
+    sub_style_id: u64,
+    #[serde(rename = "selectedPerkIds")]
+    selected_perk_ids: &'a [u64],
+}
+
+impl LcuRestClient {
+    /// `insecure_tls` should come straight from `config::Config::insecure_lcu_tls`
+    /// — the same flag `LcuClient::conn_ws` reads — so the websocket and REST
+    /// connections to the LCU always agree on whether its cert is pinned.
+    pub fn new(auth_url: &str, insecure_tls: bool) -> anyhow::Result<Self> {
+        let url = reqwest::Url::parse(&format!("https://{}", auth_url))?;
+        let credentials = format!("{}:{}", url.username(), url.password().unwrap_or_default());
+        let mut auth_value = HeaderValue::from_str(&format!(
+            "Basic {}",
+            base64::encode(&credentials)
+        ))?;
+        auth_value.set_sensitive(true);
+
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::AUTHORIZATION, auth_value);
+
+        let builder = reqwest::Client::builder().default_headers(headers);
+        let builder = if insecure_tls {
+            builder.danger_accept_invalid_certs(true)
+        } else {
+            builder.use_preconfigured_tls(crate::tls::riot_client_config()?)
+        };
+        let client = builder.build()?;
+
+        Ok(Self {
+            client,
+            base_url: format!("https://{}:{}", url.host_str().unwrap_or("127.0.0.1"), url.port().unwrap_or(0)),
+        })
+    }
+
+    async fn current_rune_page(&self) -> anyhow::Result<CurrentRunePage> {
+        let res = self
+            .client
+            .get(format!("{}/lol-perks/v1/currentpage", self.base_url))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<CurrentRunePage>()
+            .await?;
+        Ok(res)
+    }
+
+    async fn delete_rune_page(&self, id: u64) -> anyhow::Result<()> {
+        self.client
+            .delete(format!("{}/lol-perks/v1/pages/{}", self.base_url, id))
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    async fn create_rune_page(&self, rune: &Rune) -> anyhow::Result<()> {
+        let page = NewRunePage {
+            name: &rune.name,
+            primary_style_id: rune.primary_style_id,
+            sub_style_id: rune.sub_style_id,
+            selected_perk_ids: &rune.selected_perk_ids,
+        };
+
+        self.client
+            .post(format!("{}/lol-perks/v1/pages", self.base_url))
+            .json(&page)
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    /// Replaces the LCU's active rune page with `rune`, flipping `loading_runes`
+    /// around the request so the UI can show a spinner.
+    pub async fn apply_rune_page(&self, rune: &Rune, loading_runes: &Arc<Mutex<bool>>) -> anyhow::Result<()> {
+        *loading_runes.lock().unwrap() = true;
+
+        let result = async {
+            if let Ok(current) = self.current_rune_page().await {
+                let _ = self.delete_rune_page(current.id).await;
+            }
+            self.create_rune_page(rune).await
+        }
+        .await;
+
+        *loading_runes.lock().unwrap() = false;
+        result
+    }
+
+    /// Replaces a summoner's custom item sets, flipping `applying_builds` around
+    /// the request so the UI can show a spinner.
+    pub async fn apply_item_set(
+        &self,
+        summoner_id: u64,
+        item_sets: Value,
+        applying_builds: &Arc<Mutex<bool>>,
+    ) -> anyhow::Result<()> {
+        *applying_builds.lock().unwrap() = true;
+
+        let result = async {
+            self.client
+                .put(format!(
+                    "{}/lol-item-sets/v1/item-sets/{}/sets",
+                    self.base_url, summoner_id
+                ))
+                .json(&item_sets)
+                .send()
+                .await?
+                .error_for_status()?;
+            Ok(())
+        }
+        .await;
+
+        *applying_builds.lock().unwrap() = false;
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rune_page_matches_lcu_perks_field_names() {
+        let perks = [8100_u64, 8120, 8138];
+        let page = NewRunePage {
+            name: "ChampR",
+            primary_style_id: 8100,
+            sub_style_id: 8000,
+            selected_perk_ids: &perks,
+        };
+
+        let json = serde_json::to_value(&page).unwrap();
+        assert_eq!(json["primaryStyleId"], 8100);
+        assert_eq!(json["subStyleId"], 8000);
+        assert_eq!(json["selectedPerkIds"], serde_json::json!([8100, 8120, 8138]));
+    }
+
+    #[test]
+    fn new_derives_base_url_from_auth_url_host_and_port() {
+        let client = LcuRestClient::new("riot:secret@127.0.0.1:12345", false).unwrap();
+        assert_eq!(client.base_url, "https://127.0.0.1:12345");
+    }
+
+    #[test]
+    fn new_respects_insecure_tls_flag() {
+        // Both branches (pinned rustls config and danger_accept_invalid_certs)
+        // must build a usable client without touching the network.
+        assert!(LcuRestClient::new("riot:secret@127.0.0.1:12345", false).is_ok());
+        assert!(LcuRestClient::new("riot:secret@127.0.0.1:12345", true).is_ok());
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::builds::Rune;
+
+/// A data source the user can enable to pull runes/builds from, e.g. op.gg or
+/// u.gg, surfaced in the source picker UI.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SourceItem {
+    pub name: String,
+    pub label: String,
+}
+
+/// The per-source fetch for `champion_id`/`position`; this is the seam
+/// `BuildAggregator` fans out across.
+///
+/// No source has a fetcher here: every call returns an error naming the
+/// source, champion and position, and no runes are fetched.
+pub async fn fetch_runes(source: &str, champion_id: u64, position: &str) -> anyhow::Result<Vec<Rune>> {
+    anyhow::bail!(
+        "no fetcher registered for source `{}` (champion {}, position {})",
+        source,
+        champion_id,
+        position
+    )
+}
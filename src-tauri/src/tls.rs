@@ -0,0 +1,45 @@
+use std::io::BufReader;
+
+use rustls::{Certificate, ClientConfig, RootCertStore};
+
+/// Riot's LCU root certificate, vendored the same way the tokio-rustls
+/// examples embed a trust anchor with `include_bytes!`.
+///
+/// NOTE: the vendored file is currently a placeholder, not the genuine
+/// Riot-published cert — see the comment header in `certs/riotgames.pem`.
+/// `config::Config::insecure_lcu_tls` defaults to `true` until it's replaced,
+/// so nothing trusts this root by default yet.
+const RIOT_ROOT_CERT_PEM: &[u8] = include_bytes!("../certs/riotgames.pem");
+
+/// Builds a `rustls` client config that trusts only the root in
+/// `RIOT_ROOT_CERT_PEM`, so `conn_ws`/`LcuRestClient` can stop accepting
+/// arbitrary certificates on localhost once that root is the genuine one.
+pub fn riot_client_config() -> anyhow::Result<ClientConfig> {
+    let mut reader = BufReader::new(RIOT_ROOT_CERT_PEM);
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut reader)? {
+        roots.add(&Certificate(cert))?;
+    }
+
+    Ok(ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_cert_parses_into_at_least_one_root() {
+        let mut reader = BufReader::new(RIOT_ROOT_CERT_PEM);
+        let certs = rustls_pemfile::certs(&mut reader).unwrap();
+        assert!(!certs.is_empty());
+    }
+
+    #[test]
+    fn riot_client_config_builds_without_error() {
+        riot_client_config().unwrap();
+    }
+}
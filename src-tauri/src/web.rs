@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Champion id -> display name, as loaded from Data Dragon's `champion.json`.
+pub type ChampionsMap = HashMap<u64, String>;
+
+/// One entry from Data Dragon's rune reforged json, used to resolve a perk id
+/// to display metadata (name, icon path) for the UI.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DataDragonRune {
+    pub id: u64,
+    pub key: String,
+    pub name: String,
+    pub icon: String,
+}
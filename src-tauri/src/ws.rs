@@ -1,7 +1,11 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_std::sync::Mutex;
-use futures_util::{SinkExt, StreamExt};
+use futures_util::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
 use http::HeaderValue;
 use native_tls::TlsConnector;
 use tokio::{net::TcpStream, sync::mpsc};
@@ -11,28 +15,117 @@ use tokio_tungstenite::{
     Connector, MaybeTlsStream, WebSocketStream,
 };
 
+use crate::lcu_events::{self, LcuEvent};
+use crate::web::ChampionsMap;
+use crate::ws_server::{StateEventKind, StateServer};
+
+type WsSink = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
+
+/// Minimum and maximum backoff between reconnect attempts while the LCU isn't
+/// accepting websocket connections yet (e.g. client still starting up).
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// How long to wait for any frame before treating the socket as stale and
+/// sending a ping; a second silent window after that tears the connection down.
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Doubles `current`, capped at `MAX_BACKOFF`, for the next reconnect attempt.
+fn next_backoff(current: Duration) -> Duration {
+    (current * 2).min(MAX_BACKOFF)
+}
+
+/// Connection lifecycle, surfaced so the UI can show a status indicator.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConnectionState {
+    Disconnected,
+    Connecting,
+    Connected,
+    Backoff { next_attempt: Duration },
+}
+
 #[derive(Clone, Debug)]
 pub struct LcuClient {
-    pub socket: Option<Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>>,
-    pub auth_url: String,
+    pub socket: Arc<Mutex<Option<WsSink>>>,
+    pub auth_url: Arc<std::sync::Mutex<String>>,
     pub is_lcu_running: bool,
+    /// Shared with `ChampR::current_champion_id` — set directly, not mirrored
+    /// into a private copy, so the rest of the app observes picks as they land.
+    pub current_champion_id: Arc<std::sync::Mutex<Option<u64>>>,
+    /// Shared with `ChampR::current_champion`; resolved from `champions_map`
+    /// whenever `current_champion_id` changes.
+    pub current_champion: Arc<std::sync::Mutex<String>>,
+    champions_map: Arc<std::sync::Mutex<ChampionsMap>>,
+    pub state: Arc<std::sync::Mutex<ConnectionState>>,
+    /// Mirrors `config::Config::insecure_lcu_tls`; when true, falls back to
+    /// the old `danger_accept_invalid_certs` behavior instead of pinning
+    /// Riot's root certificate. Not off by default: `config::Config` defaults
+    /// it to `true`, so the pinned root is only used when the config sets it
+    /// to `false`.
+    pub insecure_tls: bool,
+    /// Broadcasts connect/disconnect and champ-select pushes to local
+    /// overlay/dashboard clients, if one has been attached.
+    pub state_server: Option<StateServer>,
+    event_tx: mpsc::UnboundedSender<LcuEvent>,
+    event_rx: Arc<std::sync::Mutex<Option<mpsc::UnboundedReceiver<LcuEvent>>>>,
 }
 
 impl LcuClient {
-    pub fn new() -> Self {
+    /// `insecure_tls` should come straight from `config::Config::insecure_lcu_tls`
+    /// — the same flag `LcuRestClient::new` takes — so the websocket and REST
+    /// connections to the LCU always agree on whether its cert is pinned.
+    ///
+    /// `current_champion_id`/`current_champion`/`champions_map` should be
+    /// `ChampR`'s own fields, not fresh ones — `dispatch_events` writes the id
+    /// and resolved name directly into them on every champ-select pick so the
+    /// rest of the app observes the change instead of it being stuck inside a
+    /// private copy only `LcuClient` can see.
+    pub fn new(
+        insecure_tls: bool,
+        current_champion_id: Arc<std::sync::Mutex<Option<u64>>>,
+        current_champion: Arc<std::sync::Mutex<String>>,
+        champions_map: Arc<std::sync::Mutex<ChampionsMap>>,
+    ) -> Self {
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
         Self {
-            socket: None,
-            auth_url: String::from(""),
+            socket: Arc::new(Mutex::new(None)),
+            auth_url: Arc::new(std::sync::Mutex::new(String::new())),
             is_lcu_running: false,
+            current_champion_id,
+            current_champion,
+            champions_map,
+            state: Arc::new(std::sync::Mutex::new(ConnectionState::Disconnected)),
+            insecure_tls,
+            state_server: None,
+            event_tx,
+            event_rx: Arc::new(std::sync::Mutex::new(Some(event_rx))),
         }
     }
 
+    pub fn connection_state(&self) -> ConnectionState {
+        self.state.lock().unwrap().clone()
+    }
+
+    /// Attaches a `StateServer` so connect/disconnect and champ-select pushes
+    /// get broadcast to overlays/dashboards. Without this, `state_server`
+    /// stays `None` and every `broadcast` call in this client is a no-op.
+    pub fn attach_state_server(&mut self, server: StateServer) {
+        self.state_server = Some(server);
+    }
+
+    /// Takes the receiving half of the typed event channel so the rest of `ChampR`
+    /// can react to champ-select/gameflow pushes. Can only be taken once.
+    pub fn take_event_receiver(&self) -> Option<mpsc::UnboundedReceiver<LcuEvent>> {
+        self.event_rx.lock().unwrap().take()
+    }
+
     pub fn update_auth_url(&mut self, url: &String) -> bool {
-        if self.auth_url.eq(url) {
+        let mut auth_url = self.auth_url.lock().unwrap();
+        if auth_url.eq(url) {
             return false;
         }
 
-        self.auth_url = url.to_string();
+        *auth_url = url.to_string();
         println!("[LcuClient] updated auth url to {}", url);
         true
     }
@@ -42,30 +135,48 @@ impl LcuClient {
         if !s {}
     }
 
-    pub async fn close_ws(&mut self) {
-        match &self.socket {
-            None => (),
-            Some(s) => {
-                let mut s = s.lock().await;
-                let _ = s.close(None);
-            }
+    /// Tears the connection down: closes the socket, clears it and `auth_url`
+    /// so nothing attempts a stale-socket write before the next reconnect,
+    /// marks the state `Disconnected`, and broadcasts that to any attached
+    /// `state_server`. Shared by the explicit `watch_cmd_output` close path
+    /// and `dispatch_events`' heartbeat-timeout/read-error teardown, so a
+    /// connection drop is visible to overlay/dashboard clients either way.
+    async fn teardown(
+        socket: &Arc<Mutex<Option<WsSink>>>,
+        auth_url: &Arc<std::sync::Mutex<String>>,
+        state: &Arc<std::sync::Mutex<ConnectionState>>,
+        state_server: &Option<StateServer>,
+    ) {
+        if let Some(mut s) = socket.lock().await.take() {
+            let _ = s.close().await;
         }
 
-        self.socket = None;
-        self.auth_url = String::new();
+        auth_url.lock().unwrap().clear();
+        *state.lock().unwrap() = ConnectionState::Disconnected;
+
+        if let Some(server) = state_server {
+            server.broadcast(StateEventKind::LcuDisconnected).await;
+        }
+    }
+
+    pub async fn close_ws(&mut self) {
+        Self::teardown(&self.socket, &self.auth_url, &self.state, &self.state_server).await;
     }
 
     pub async fn watch_cmd_output(&mut self) {
         let (tx, mut rx) = mpsc::unbounded_channel();
-        let handle = tokio::task::spawn_blocking(move || loop {
-            let ret = crate::cmd::get_commandline();
-            match tx.send(ret) {
-                Ok(_) => (),
-                Err(e) => {
-                    println!("{:?}", e.to_string());
+        let handle = tokio::task::spawn(async move {
+            loop {
+                let ret = tokio::task::spawn_blocking(crate::cmd::get_commandline)
+                    .await
+                    .unwrap_or_default();
+
+                if tx.send(ret).is_err() {
+                    break;
                 }
-            };
-            std::thread::sleep(std::time::Duration::from_millis(5000));
+
+                tokio::time::sleep(Duration::from_millis(5000)).await;
+            }
         });
 
         while let Some((auth_url, running)) = rx.recv().await {
@@ -79,7 +190,8 @@ impl LcuClient {
             }
 
             let updated = self.update_auth_url(&auth_url);
-            if !updated {
+            let stale = matches!(self.connection_state(), ConnectionState::Disconnected);
+            if !updated && !stale {
                 continue;
             }
 
@@ -90,24 +202,31 @@ impl LcuClient {
     }
 
     pub async fn conn_ws(&mut self) -> anyhow::Result<()> {
-        let wsurl = format!("wss://{}", &self.auth_url);
+        let auth_url = self.auth_url.lock().unwrap().clone();
+        let wsurl = format!("wss://{}", &auth_url);
         let url = reqwest::Url::parse(&wsurl).unwrap();
         let credentials = format!("{}:{}", url.username(), url.password().unwrap());
 
+        *self.state.lock().unwrap() = ConnectionState::Connecting;
+
+        let mut backoff = INITIAL_BACKOFF;
         let mut socket;
         loop {
-            // retry in 2s if failed
             let mut req = url.to_string().into_client_request()?;
             let cred_value =
                 HeaderValue::from_str(&format!("Basic {}", base64::encode(&credentials)))?;
             req.headers_mut().insert("Authorization", cred_value);
 
-            let connector = Connector::NativeTls(
-                TlsConnector::builder()
-                    .danger_accept_invalid_certs(true)
-                    .build()
-                    .unwrap(),
-            );
+            let connector = if self.insecure_tls {
+                Connector::NativeTls(
+                    TlsConnector::builder()
+                        .danger_accept_invalid_certs(true)
+                        .build()
+                        .unwrap(),
+                )
+            } else {
+                Connector::Rustls(Arc::new(crate::tls::riot_client_config()?))
+            };
             match connect_async_tls_with_config::<http::Request<()>>(
                 req,
                 Some(WebSocketConfig::default()),
@@ -120,8 +239,12 @@ impl LcuClient {
                     break;
                 }
                 Err(_) => {
-                    // server not ready
-                    std::thread::sleep(std::time::Duration::from_millis(2000));
+                    // server not ready yet; back off and try again
+                    *self.state.lock().unwrap() = ConnectionState::Backoff {
+                        next_attempt: backoff,
+                    };
+                    tokio::time::sleep(backoff).await;
+                    backoff = next_backoff(backoff);
                 }
             };
         }
@@ -130,16 +253,128 @@ impl LcuClient {
         socket
             .send(Message::Text(r#"[5, "OnJsonApiEvent"]"#.to_string()))
             .await?;
-        while let Some(msg) = socket.next().await {
-            let msg = msg?;
-            let msg = msg.to_text().unwrap();
-            println!("{:?}", &msg.len());
+
+        let (write, read) = socket.split();
+        *self.socket.lock().await = Some(write);
+        *self.state.lock().unwrap() = ConnectionState::Connected;
+
+        if let Some(server) = &self.state_server {
+            server.broadcast(StateEventKind::LcuConnected).await;
         }
 
-        self.socket = Some(Arc::new(Mutex::new(socket)));
+        let event_tx = self.event_tx.clone();
+        let current_champion_id = self.current_champion_id.clone();
+        let current_champion = self.current_champion.clone();
+        let champions_map = self.champions_map.clone();
+        let socket = self.socket.clone();
+        let auth_url = self.auth_url.clone();
+        let state = self.state.clone();
+        let state_server = self.state_server.clone();
+        tokio::task::spawn(Self::dispatch_events(
+            read,
+            event_tx,
+            current_champion_id,
+            current_champion,
+            champions_map,
+            socket,
+            auth_url,
+            state,
+            state_server,
+        ));
+
         Ok(())
     }
 
+    /// Owns the read loop: decodes every `OnJsonApiEvent` frame into a typed
+    /// `LcuEvent`, updates the bits of state `ChampR` needs eagerly (so rune
+    /// fetching can trigger on pick), and forwards the event to subscribers.
+    ///
+    /// Also acts as the heartbeat: if no frame arrives within
+    /// `HEARTBEAT_TIMEOUT` a ping is sent, and a second silent window tears the
+    /// connection down so `watch_cmd_output` notices `Disconnected` and
+    /// reconnects.
+    async fn dispatch_events(
+        mut read: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+        event_tx: mpsc::UnboundedSender<LcuEvent>,
+        current_champion_id: Arc<std::sync::Mutex<Option<u64>>>,
+        current_champion: Arc<std::sync::Mutex<String>>,
+        champions_map: Arc<std::sync::Mutex<ChampionsMap>>,
+        socket: Arc<Mutex<Option<WsSink>>>,
+        auth_url: Arc<std::sync::Mutex<String>>,
+        state: Arc<std::sync::Mutex<ConnectionState>>,
+        state_server: Option<StateServer>,
+    ) {
+        let mut missed_heartbeat = false;
+
+        loop {
+            match tokio::time::timeout(HEARTBEAT_TIMEOUT, read.next()).await {
+                Ok(Some(Ok(msg))) => {
+                    missed_heartbeat = false;
+
+                    let text = match msg.to_text() {
+                        Ok(t) => t,
+                        Err(_) => continue,
+                    };
+
+                    let event = match lcu_events::parse_frame(text) {
+                        Some(e) => e,
+                        None => continue,
+                    };
+
+                    if let LcuEvent::ChampSelectSession(ref session) = event {
+                        if let Some(champion_id) = session.current_champion_id() {
+                            // The LCU re-sends this session on every ban/hover/timer
+                            // tick during champ select, not just on an actual pick;
+                            // only broadcast when the champion actually changed.
+                            let mut current = current_champion_id.lock().unwrap();
+                            let changed = *current != Some(champion_id);
+                            *current = Some(champion_id);
+                            drop(current);
+
+                            if changed {
+                                let name = champions_map
+                                    .lock()
+                                    .unwrap()
+                                    .get(&champion_id)
+                                    .cloned()
+                                    .unwrap_or_default();
+                                *current_champion.lock().unwrap() = name;
+
+                                if let Some(server) = &state_server {
+                                    server
+                                        .broadcast(StateEventKind::ChampionPicked { champion_id })
+                                        .await;
+                                }
+                            }
+                        }
+                    }
+
+                    if event_tx.send(event).is_err() {
+                        break;
+                    }
+                }
+                Ok(Some(Err(e))) => {
+                    println!("[ws] socket read error: {:?}", e);
+                    break;
+                }
+                Ok(None) => break,
+                Err(_) => {
+                    if missed_heartbeat {
+                        println!("[ws] heartbeat timed out, tearing down connection");
+                        break;
+                    }
+
+                    missed_heartbeat = true;
+                    if let Some(sink) = socket.lock().await.as_mut() {
+                        let _ = sink.send(Message::Ping(Vec::new())).await;
+                    }
+                }
+            }
+        }
+
+        Self::teardown(&socket, &auth_url, &state, &state_server).await;
+    }
+
     pub async fn on_ws_close(&mut self) {}
 }
 
@@ -147,9 +382,61 @@ impl LcuClient {
 mod tests {
     use super::*;
 
+    fn test_client(insecure_tls: bool) -> LcuClient {
+        LcuClient::new(
+            insecure_tls,
+            Arc::new(std::sync::Mutex::new(None)),
+            Arc::new(std::sync::Mutex::new(String::new())),
+            Arc::new(std::sync::Mutex::new(ChampionsMap::new())),
+        )
+    }
+
     #[tokio::test]
     async fn start() {
-        let mut lcu = LcuClient::new();
+        let mut lcu = test_client(false);
         lcu.watch_cmd_output().await;
     }
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let mut backoff = INITIAL_BACKOFF;
+        for _ in 0..4 {
+            backoff = next_backoff(backoff);
+        }
+        assert_eq!(backoff, Duration::from_secs(8));
+
+        // Keep doubling well past the cap; it should never exceed MAX_BACKOFF.
+        for _ in 0..10 {
+            backoff = next_backoff(backoff);
+        }
+        assert_eq!(backoff, MAX_BACKOFF);
+    }
+
+    #[test]
+    fn new_client_starts_disconnected() {
+        let lcu = test_client(false);
+        assert_eq!(lcu.connection_state(), ConnectionState::Disconnected);
+    }
+
+    #[test]
+    fn current_champion_id_is_the_shared_instance_not_a_copy() {
+        let shared = Arc::new(std::sync::Mutex::new(Some(42)));
+        let lcu = LcuClient::new(
+            false,
+            shared.clone(),
+            Arc::new(std::sync::Mutex::new(String::new())),
+            Arc::new(std::sync::Mutex::new(ChampionsMap::new())),
+        );
+
+        *shared.lock().unwrap() = Some(99);
+        assert_eq!(*lcu.current_champion_id.lock().unwrap(), Some(99));
+    }
+
+    #[test]
+    fn attach_state_server_replaces_the_default_none() {
+        let mut lcu = test_client(false);
+        assert!(lcu.state_server.is_none());
+        lcu.attach_state_server(StateServer::new());
+        assert!(lcu.state_server.is_some());
+    }
 }
@@ -0,0 +1,159 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, Mutex},
+};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::builds::Rune;
+
+/// One versioned push to every connected overlay/dashboard client.
+///
+/// Mirrors ExtraChat's `ResponseContainer { number, kind }` envelope so
+/// clients can correlate a push against the event that produced it and
+/// detect a dropped message from a gap in `number`.
+#[derive(Clone, Debug, Serialize)]
+pub struct StateEvent {
+    pub number: u64,
+    pub kind: StateEventKind,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", content = "data")]
+pub enum StateEventKind {
+    ChampionPicked { champion_id: u64 },
+    RunesLoaded { runes: Vec<Rune> },
+    BuildApplied { source: String },
+    LcuConnected,
+    LcuDisconnected,
+}
+
+type ClientId = u64;
+type ClientTx = mpsc::UnboundedSender<Message>;
+
+/// Embedded websocket server broadcasting `ChampR`'s live state (champion
+/// picked, runes loaded, build applied, LCU connect/disconnect) to local
+/// overlays or a browser dashboard, so they don't have to poll the LCU
+/// directly themselves.
+#[derive(Clone, Default)]
+pub struct StateServer {
+    clients: Arc<Mutex<HashMap<ClientId, ClientTx>>>,
+    next_client_id: Arc<AtomicU64>,
+    next_event_number: Arc<AtomicU64>,
+}
+
+impl StateServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds `addr` and accepts connections until the process exits.
+    pub async fn listen(&self, addr: SocketAddr) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        println!("[ws-server] listening on {}", addr);
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::task::spawn(async move {
+                if let Err(e) = server.accept_client(stream).await {
+                    println!("[ws-server] client error: {:?}", e);
+                }
+            });
+        }
+    }
+
+    async fn accept_client(&self, stream: TcpStream) -> anyhow::Result<()> {
+        let ws_stream = tokio_tungstenite::accept_async(stream).await?;
+        let (mut write, mut read) = ws_stream.split();
+
+        let id = self.next_client_id.fetch_add(1, Ordering::SeqCst);
+        let (tx, mut rx) = mpsc::unbounded_channel::<Message>();
+        self.clients.lock().await.insert(id, tx);
+        println!("[ws-server] client {} connected", id);
+
+        let clients = self.clients.clone();
+        tokio::task::spawn(async move {
+            while let Some(msg) = rx.recv().await {
+                if write.send(msg).await.is_err() {
+                    break;
+                }
+            }
+            clients.lock().await.remove(&id);
+        });
+
+        // Overlays/dashboards are push-only subscribers; drain incoming frames
+        // just to notice the client closing the connection.
+        while let Some(msg) = read.next().await {
+            if msg.is_err() {
+                break;
+            }
+        }
+
+        self.clients.lock().await.remove(&id);
+        println!("[ws-server] client {} disconnected", id);
+        Ok(())
+    }
+
+    /// Pushes `kind` to every connected client, tagging it with a
+    /// monotonically increasing `number` so clients can detect gaps.
+    pub async fn broadcast(&self, kind: StateEventKind) {
+        let number = self.next_event_number.fetch_add(1, Ordering::SeqCst);
+        let event = StateEvent { number, kind };
+        let text = match serde_json::to_string(&event) {
+            Ok(t) => t,
+            Err(_) => return,
+        };
+
+        let clients = self.clients.lock().await;
+        for tx in clients.values() {
+            let _ = tx.send(Message::Text(text.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn broadcast_numbers_increment_and_encode_the_kind() {
+        let server = StateServer::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        server.clients.lock().await.insert(0, tx);
+
+        server.broadcast(StateEventKind::LcuConnected).await;
+        server
+            .broadcast(StateEventKind::ChampionPicked { champion_id: 99 })
+            .await;
+
+        let first = rx.recv().await.unwrap();
+        let second = rx.recv().await.unwrap();
+
+        let first: serde_json::Value = serde_json::from_str(first.to_text().unwrap()).unwrap();
+        let second: serde_json::Value = serde_json::from_str(second.to_text().unwrap()).unwrap();
+
+        assert_eq!(first["number"], 0);
+        assert_eq!(first["kind"]["type"], "LcuConnected");
+
+        assert_eq!(second["number"], 1);
+        assert_eq!(second["kind"]["type"], "ChampionPicked");
+        assert_eq!(second["kind"]["data"]["champion_id"], 99);
+    }
+
+    #[tokio::test]
+    async fn broadcast_with_no_clients_is_a_no_op() {
+        let server = StateServer::new();
+        server.broadcast(StateEventKind::LcuDisconnected).await;
+    }
+}
@@ -2,7 +2,17 @@ use std::sync::{Arc, Mutex};
 
 use bytes::Bytes;
 
-use crate::{builds::Rune, source::SourceItem, web::{ChampionsMap, DataDragonRune}, config};
+use crate::{
+    aggregator::BuildAggregator,
+    builds::Rune,
+    config,
+    lcu_events::LcuEvent,
+    lcu_rest::LcuRestClient,
+    source::SourceItem,
+    web::{ChampionsMap, DataDragonRune},
+    ws::LcuClient,
+    ws_server::{StateEventKind, StateServer},
+};
 
 pub type LogItem = (String, String);
 
@@ -25,7 +35,7 @@ pub struct ChampR {
     pub fetched_remote_data: Arc<Mutex<bool>>,
     pub remote_rune_list: Arc<Mutex<Vec<DataDragonRune>>>,
     pub rune_images: Arc<Mutex<Vec<(Bytes, Bytes, Bytes)>>>,
-    pub applying_builds: bool,
+    pub applying_builds: Arc<Mutex<bool>>,
 }
 
 impl ChampR {
@@ -63,4 +73,131 @@ impl ChampR {
             ..Default::default()
         }
     }
+
+    /// Connects to the LCU and drives champ-select picks end-to-end: attaches
+    /// `state_server` to the client so it gets connect/disconnect and
+    /// champion-picked pushes, then on every pick fetches/merges runes via a
+    /// `BuildAggregator`, pushes the top-ranked page into the live client via
+    /// `LcuRestClient`, and broadcasts `RunesLoaded`/`BuildApplied`. Runs
+    /// until the LCU event channel closes.
+    pub async fn run(&self, state_server: StateServer) {
+        let insecure_tls = self.app_config.lock().unwrap().insecure_lcu_tls;
+        let mut lcu = LcuClient::new(
+            insecure_tls,
+            self.current_champion_id.clone(),
+            self.current_champion.clone(),
+            self.champions_map.clone(),
+        );
+        lcu.attach_state_server(state_server.clone());
+
+        let auth_url = lcu.auth_url.clone();
+        let mut events = lcu
+            .take_event_receiver()
+            .expect("LcuClient's event receiver was already taken");
+
+        tokio::task::spawn(async move {
+            lcu.watch_cmd_output().await;
+        });
+
+        let aggregator = BuildAggregator::new(self.logs.clone());
+        // Rebuilt only when `auth_url` changes, so repeated applies reuse the
+        // same pooled `reqwest::Client` instead of redoing the TLS handshake.
+        let mut rest_client: Option<(String, LcuRestClient)> = None;
+        // The LCU resends the session on every champ-select tick; only act
+        // when the local player's champion actually changes.
+        let mut last_champion_id: Option<u64> = None;
+
+        while let Some(event) = events.recv().await {
+            let session = match event {
+                LcuEvent::ChampSelectSession(session) => session,
+                _ => continue,
+            };
+            let champion_id = match session.current_champion_id() {
+                Some(id) => id,
+                None => {
+                    last_champion_id = None;
+                    continue;
+                }
+            };
+            if last_champion_id == Some(champion_id) {
+                continue;
+            }
+            last_champion_id = Some(champion_id);
+
+            let current_auth_url = auth_url.lock().unwrap().clone();
+            if rest_client.as_ref().map(|(url, _)| url.as_str()) != Some(current_auth_url.as_str())
+            {
+                rest_client = LcuRestClient::new(&current_auth_url, insecure_tls)
+                    .ok()
+                    .map(|client| (current_auth_url, client));
+            }
+
+            self.handle_champion_pick(
+                champion_id,
+                &aggregator,
+                rest_client.as_ref().map(|(_, client)| client),
+                &state_server,
+            )
+            .await;
+        }
+    }
+
+    /// Fetches/merges runes for `champion_id` across `selected_sources`,
+    /// stores the result into `current_champion_runes`, broadcasts
+    /// `RunesLoaded`, then pushes the top-ranked page to the live client via
+    /// `rest_client` (if connected) and broadcasts `BuildApplied` on success.
+    async fn handle_champion_pick(
+        &self,
+        champion_id: u64,
+        aggregator: &BuildAggregator,
+        rest_client: Option<&LcuRestClient>,
+        state_server: &StateServer,
+    ) {
+        // Champ-select doesn't surface the player's assigned role in this
+        // chunk; position-aware fetching is a follow-up.
+        let position = "any";
+
+        let selected_names = self.selected_sources.lock().unwrap().clone();
+        let sources: Vec<SourceItem> = self
+            .source_list
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| selected_names.contains(&s.name))
+            .cloned()
+            .collect();
+
+        let runes = aggregator
+            .aggregate_for_champion(champion_id, position, &sources)
+            .await;
+        *self.current_champion_runes.lock().unwrap() = runes.clone();
+
+        state_server
+            .broadcast(StateEventKind::RunesLoaded {
+                runes: runes.clone(),
+            })
+            .await;
+
+        let best = match runes.first() {
+            Some(rune) => rune,
+            None => return,
+        };
+        let client = match rest_client {
+            Some(client) => client,
+            None => return,
+        };
+
+        match client.apply_rune_page(best, &self.loading_runes).await {
+            Ok(()) => {
+                state_server
+                    .broadcast(StateEventKind::BuildApplied {
+                        source: best.source.clone(),
+                    })
+                    .await;
+            }
+            Err(e) => {
+                self.logs.lock().unwrap().push((best.source.clone(), e.to_string()));
+            }
+        }
+    }
 }